@@ -77,6 +77,15 @@ impl From<[u8; HASH_BYTES]> for Hash {
     }
 }
 
+impl TryFrom<&[u8]> for Hash {
+    type Error = std::array::TryFromSliceError;
+
+    #[inline]
+    fn try_from(hash_slice: &[u8]) -> Result<Self, Self::Error> {
+        <[u8; HASH_BYTES]>::try_from(hash_slice).map(Self::from)
+    }
+}
+
 impl AsRef<[u8]> for Hash {
     fn as_ref(&self) -> &[u8] {
         &self.0[..]
@@ -192,6 +201,15 @@ mod tests {
         assert!(Hash::new_unique() != Hash::new_unique());
     }
 
+    #[test]
+    fn test_hash_try_from_slice() {
+        let hash = hash(&[1u8]);
+        assert_eq!(Hash::try_from(hash.as_ref()).unwrap(), hash);
+        assert!(Hash::try_from(&hash.as_ref()[..HASH_BYTES - 1]).is_err());
+        assert!(Hash::try_from(&[0u8; HASH_BYTES + 1][..]).is_err());
+        assert!(Hash::try_from(&[][..]).is_err());
+    }
+
     #[test]
     fn test_hash_fromstr() {
         let hash = hash(&[1u8]);